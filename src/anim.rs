@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::time::Duration;
 
 use bevy::prelude::*;
@@ -24,7 +25,49 @@ impl AsepriteTag {
     }
 }
 
-#[derive(Debug, Component, PartialEq, Eq)]
+/// The playback state of an [`AsepriteAnimation`], mirroring the repeat
+/// count in effect: the tag's authored one, or a playlist's [`RepeatMode`]
+/// override.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AsepritePlaybackStatus {
+    /// Playing a tag with no repeat limit (loops forever).
+    Playing,
+    /// Playing a tag with a finite repeat count that hasn't been exhausted yet.
+    Repeating,
+    /// A finite-repeat tag has played its number of repeats and is holding
+    /// on its final frame.
+    Stopped,
+}
+
+/// Overrides how many times a queued [`AsepriteAnimationPlaylist`] entry
+/// repeats, independent of the tag's authored repeat count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepeatMode {
+    /// Use the repeat count authored on the tag itself.
+    Tag,
+    /// Loop forever, ignoring the tag's authored repeat count.
+    Infinite,
+    /// Repeat this many times, ignoring the tag's authored repeat count.
+    Count(u32),
+}
+
+/// Fired when an [`AsepriteAnimation`] completes a full cycle of its tag
+/// (wraps back to where it started) and keeps playing.
+#[derive(Debug, Clone, Event)]
+pub struct AsepriteAnimationLooped {
+    pub entity: Entity,
+    pub tag: Option<String>,
+}
+
+/// Fired when an [`AsepriteAnimation`] plays the last repeat of a
+/// finite-repeat tag and comes to rest on its final frame.
+#[derive(Debug, Clone, Event)]
+pub struct AsepriteAnimationFinished {
+    pub entity: Entity,
+    pub tag: Option<String>,
+}
+
+#[derive(Debug, Component, PartialEq)]
 pub struct AsepriteAnimation {
     is_playing: bool,
     tag: Option<String>,
@@ -32,6 +75,11 @@ pub struct AsepriteAnimation {
     forward: bool,
     time_elapsed: Duration,
     tag_changed: bool,
+    remaining_repeats: Option<u32>,
+    speed: f32,
+    max_frame_duration: Option<Duration>,
+    pending_goto: Option<(usize, bool)>,
+    repeat_override: Option<RepeatMode>,
 }
 
 impl Default for AsepriteAnimation {
@@ -43,10 +91,67 @@ impl Default for AsepriteAnimation {
             forward: default(),
             time_elapsed: default(),
             tag_changed: true,
+            remaining_repeats: None,
+            speed: 1.0,
+            max_frame_duration: None,
+            pending_goto: None,
+            repeat_override: None,
+        }
+    }
+}
+
+/// One tick of `direction`'s frame-stepping rule over `range`. Returns
+/// `(next_frame, next_forward, cycle_completed)`.
+fn step_frame(
+    current_frame: usize,
+    forward: bool,
+    direction: reader::raw::AsepriteAnimationDirection,
+    range: &std::ops::Range<u16>,
+) -> (usize, bool, bool) {
+    use reader::raw::AsepriteAnimationDirection;
+    match direction {
+        AsepriteAnimationDirection::Forward => {
+            let next_frame = current_frame + 1;
+            if range.contains(&(next_frame as u16)) {
+                (next_frame, forward, false)
+            } else {
+                (range.start as usize, forward, true)
+            }
+        }
+        AsepriteAnimationDirection::Reverse => match current_frame.checked_sub(1) {
+            Some(next_frame) if range.contains(&(next_frame as u16)) => {
+                (next_frame, forward, false)
+            }
+            _ => (range.end as usize - 1, forward, true),
+        },
+        AsepriteAnimationDirection::PingPong => {
+            if forward {
+                let next_frame = current_frame + 1;
+                if range.contains(&(next_frame as u16)) {
+                    (next_frame, true, false)
+                } else {
+                    (next_frame.saturating_sub(1), false, false)
+                }
+            } else {
+                match current_frame.checked_sub(1) {
+                    Some(next_frame) if range.contains(&(next_frame as u16)) => {
+                        (next_frame, false, false)
+                    }
+                    _ => (range.start as usize, true, true),
+                }
+            }
         }
     }
 }
 
+/// Applies an optional [`AsepriteAnimation::max_frame_duration`] clamp, ignoring a non-positive one.
+fn clamp_frame_duration(duration: Duration, max_frame_duration: Option<Duration>) -> Duration {
+    match max_frame_duration {
+        Some(max) if max > Duration::ZERO => duration.min(max),
+        _ => duration,
+    }
+}
+
 impl AsepriteAnimation {
     fn reset(&mut self, info: &AsepriteInfo) {
         self.tag_changed = false;
@@ -56,11 +161,25 @@ impl AsepriteAnimation {
                     Some(tag) => tag,
                     None => {
                         error!("Tag {} wasn't found.", tag);
+                        // Don't leave a stale `Stopped`-producing repeat count
+                        // around: that would masquerade as a finished tag and
+                        // let `update_playlists` cascade through the rest of
+                        // the queue.
+                        self.remaining_repeats = None;
                         return;
                     }
                 };
 
                 let range = tag.frames.clone();
+                self.remaining_repeats = match self.repeat_override.take() {
+                    Some(RepeatMode::Infinite) => None,
+                    Some(RepeatMode::Count(count)) => Some(count),
+                    // A `repeat` of 0 means the tag has no authored repeat limit.
+                    Some(RepeatMode::Tag) | None => match tag.repeat {
+                        0 => None,
+                        repeat => Some(repeat as u32),
+                    },
+                };
                 use reader::raw::AsepriteAnimationDirection;
                 match tag.animation_direction {
                     AsepriteAnimationDirection::Forward | AsepriteAnimationDirection::PingPong => {
@@ -76,67 +195,103 @@ impl AsepriteAnimation {
             None => {
                 self.current_frame = 0;
                 self.forward = true;
+                self.remaining_repeats = None;
+                self.repeat_override = None;
             }
         }
     }
 
-    fn next_frame(&mut self, info: &AsepriteInfo) {
+    /// Accounts for a completed cycle, stopping playback once repeats are exhausted.
+    fn finish_cycle(
+        &mut self,
+        direction: reader::raw::AsepriteAnimationDirection,
+        range: &std::ops::Range<u16>,
+    ) {
+        use reader::raw::AsepriteAnimationDirection;
+        if let Some(remaining) = self.remaining_repeats.as_mut() {
+            *remaining = remaining.saturating_sub(1);
+            if *remaining == 0 {
+                self.current_frame = match direction {
+                    AsepriteAnimationDirection::Forward => range.end as usize - 1,
+                    AsepriteAnimationDirection::Reverse | AsepriteAnimationDirection::PingPong => {
+                        range.start as usize
+                    }
+                };
+                self.is_playing = false;
+            }
+        }
+    }
+
+    /// Advances to the next frame. Returns `true` if this step completed a
+    /// full cycle of the tag (i.e. it wrapped back to where it started).
+    fn next_frame(&mut self, info: &AsepriteInfo) -> bool {
         match &self.tag {
             Some(tag) => {
                 let tag = match info.tags.get(tag) {
                     Some(tag) => tag,
                     None => {
                         error!("Tag {} wasn't found.", tag);
-                        return;
+                        return false;
                     }
                 };
 
                 let range = tag.frames.clone();
-                match tag.animation_direction {
-                    reader::raw::AsepriteAnimationDirection::Forward => {
-                        let next_frame = self.current_frame + 1;
-                        if range.contains(&(next_frame as u16)) {
-                            self.current_frame = next_frame;
-                        } else {
-                            self.current_frame = range.start as usize;
-                        }
-                    }
-                    reader::raw::AsepriteAnimationDirection::Reverse => {
-                        let next_frame = self.current_frame.checked_sub(1);
-                        if let Some(next_frame) = next_frame {
-                            if range.contains(&((next_frame) as u16)) {
-                                self.current_frame = next_frame;
-                            } else {
-                                self.current_frame = range.end as usize - 1;
-                            }
-                        } else {
-                            self.current_frame = range.end as usize - 1;
-                        }
-                    }
-                    reader::raw::AsepriteAnimationDirection::PingPong => {
-                        if self.forward {
-                            let next_frame = self.current_frame + 1;
-                            if range.contains(&(next_frame as u16)) {
-                                self.current_frame = next_frame;
-                            } else {
-                                self.current_frame = next_frame.saturating_sub(1);
-                                self.forward = false;
-                            }
-                        } else {
-                            let next_frame = self.current_frame.checked_sub(1);
-                            if let Some(next_frame) = next_frame {
-                                if range.contains(&(next_frame as u16)) {
-                                    self.current_frame = next_frame
-                                }
-                            }
-                            self.current_frame += 1;
-                            self.forward = true;
-                        }
-                    }
+                let (next_frame, forward, cycle_completed) = step_frame(
+                    self.current_frame,
+                    self.forward,
+                    tag.animation_direction,
+                    &range,
+                );
+                self.current_frame = next_frame;
+                self.forward = forward;
+
+                if cycle_completed {
+                    self.finish_cycle(tag.animation_direction, &range);
                 }
+
+                cycle_completed
             }
             None => {
                 self.current_frame = (self.current_frame + 1) % info.frame_count;
+                false
+            }
+        }
+    }
+
+    /// Steps backward through the tag range regardless of its authored direction, for negative [`Self::speed`].
+    fn prev_frame(&mut self, info: &AsepriteInfo) -> bool {
+        match &self.tag {
+            Some(tag) => {
+                let tag = match info.tags.get(tag) {
+                    Some(tag) => tag,
+                    None => {
+                        error!("Tag {} wasn't found.", tag);
+                        return false;
+                    }
+                };
+
+                use reader::raw::AsepriteAnimationDirection;
+                let range = tag.frames.clone();
+                let (next_frame, _, cycle_completed) = step_frame(
+                    self.current_frame,
+                    self.forward,
+                    AsepriteAnimationDirection::Reverse,
+                    &range,
+                );
+                self.current_frame = next_frame;
+
+                if cycle_completed {
+                    self.finish_cycle(AsepriteAnimationDirection::Reverse, &range);
+                }
+
+                cycle_completed
+            }
+            None => {
+                self.current_frame = self
+                    .current_frame
+                    .checked_sub(1)
+                    .unwrap_or(info.frame_count - 1);
+                false
             }
         }
     }
@@ -145,6 +300,11 @@ impl AsepriteAnimation {
         Duration::from_millis(info.frame_infos[self.current_frame].delay_ms as u64)
     }
 
+    /// The duration a frame should be held for, with [`Self::max_frame_duration`] applied if set.
+    fn effective_frame_duration(&self, info: &AsepriteInfo) -> Duration {
+        clamp_frame_duration(self.current_frame_duration(info), self.max_frame_duration)
+    }
+
     pub fn time_elapsed(&self) -> Duration {
         self.time_elapsed
     }
@@ -163,27 +323,85 @@ impl AsepriteAnimation {
         is_last_frame && frame_finished
     }
 
-    /// Returns whether the frame was changed
-    fn update(&mut self, info: &AsepriteInfo, dt: Duration) -> bool {
-        if self.tag_changed {
+    /// Seeks to `frame`, clamped to the active tag's range, and applies `play_after`.
+    fn apply_goto(&mut self, info: &AsepriteInfo, frame: usize, play_after: bool) {
+        self.tag_changed = false;
+        let range = match &self.tag {
+            Some(tag) => match info.tags.get(tag) {
+                Some(tag) => tag.frames.start as usize..tag.frames.end as usize,
+                None => {
+                    error!("Tag {} wasn't found.", tag);
+                    return;
+                }
+            },
+            None => 0..info.frame_count,
+        };
+
+        let clamped = frame.clamp(range.start, range.end - 1);
+        if clamped != frame {
+            warn!("Requested frame {frame} is out of range {range:?}; clamping to {clamped}");
+        }
+
+        self.current_frame = clamped;
+        self.time_elapsed = Duration::ZERO;
+        self.is_playing = play_after;
+    }
+
+    /// Advances the animation by `dt` (scaled by [`Self::speed`]) and returns
+    /// what happened during this call.
+    fn update(&mut self, info: &AsepriteInfo, dt: Duration) -> AnimationUpdate {
+        // Initialize `forward`/`remaining_repeats` for the active tag before
+        // a queued seek overrides `current_frame`, so a goto issued right
+        // after a tag change doesn't get clobbered by `reset()` on the next
+        // tick.
+        let tag_just_reset = self.tag_changed;
+        if tag_just_reset {
             self.reset(info);
-            return true;
         }
 
-        if self.is_paused() {
-            return false;
+        if let Some((frame, play_after)) = self.pending_goto.take() {
+            self.apply_goto(info, frame, play_after);
+            return AnimationUpdate {
+                frame_changed: true,
+                ..default()
+            };
+        }
+
+        if tag_just_reset {
+            return AnimationUpdate {
+                frame_changed: true,
+                ..default()
+            };
         }
 
-        self.time_elapsed += dt;
-        let mut current_frame_duration = self.current_frame_duration(info);
-        let mut frame_changed = false;
+        if self.is_paused() || self.speed == 0.0 {
+            return default();
+        }
+
+        self.time_elapsed += dt.mul_f32(self.speed.abs());
+        let mut current_frame_duration = self.effective_frame_duration(info);
+        let mut update = AnimationUpdate::default();
         while self.time_elapsed >= current_frame_duration {
             self.time_elapsed -= current_frame_duration;
-            self.next_frame(info);
-            current_frame_duration = self.current_frame_duration(info);
-            frame_changed = true;
+            let cycle_completed = if self.speed < 0.0 {
+                self.prev_frame(info)
+            } else {
+                self.next_frame(info)
+            };
+            if cycle_completed {
+                if self.is_paused() {
+                    update.finished = true;
+                } else {
+                    update.loop_count += 1;
+                }
+            }
+            current_frame_duration = self.effective_frame_duration(info);
+            update.frame_changed = true;
+            if self.is_paused() {
+                break;
+            }
         }
-        frame_changed
+        update
     }
 
     /// Get the current frame
@@ -191,6 +409,22 @@ impl AsepriteAnimation {
         self.current_frame
     }
 
+    /// Jumps to an absolute frame, keeping the current play/pause state.
+    /// The seek is applied on the next [`Self::update`].
+    pub fn set_frame(&mut self, frame: usize) {
+        self.pending_goto = Some((frame, self.is_playing));
+    }
+
+    /// Seeks to `frame` and resumes playback.
+    pub fn goto_and_play(&mut self, frame: usize) {
+        self.pending_goto = Some((frame, true));
+    }
+
+    /// Seeks to `frame` and pauses.
+    pub fn goto_and_stop(&mut self, frame: usize) {
+        self.pending_goto = Some((frame, false));
+    }
+
     /// Start or resume playing an animation
     pub fn play(&mut self) {
         self.is_playing = true;
@@ -215,18 +449,66 @@ impl AsepriteAnimation {
     pub fn toggle(&mut self) {
         self.is_playing = !self.is_playing;
     }
+
+    /// Returns the current playback speed multiplier.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Sets the playback speed multiplier. Negative values play the tag in
+    /// reverse regardless of its authored direction; `0.0` behaves like
+    /// [`Self::pause`].
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed;
+    }
+
+    /// Returns the current per-frame duration clamp, if any.
+    pub fn max_frame_duration(&self) -> Option<Duration> {
+        self.max_frame_duration
+    }
+
+    /// Caps how long any single frame is held for, regardless of its
+    /// authored delay. Useful when playing back at high speed through
+    /// animations with deliberately long hold frames.
+    pub fn set_max_frame_duration(&mut self, max_frame_duration: Option<Duration>) {
+        self.max_frame_duration = max_frame_duration;
+    }
+
+    /// Returns the current playback status, reflecting the repeat count in
+    /// effect (the tag's authored one, or a playlist's [`RepeatMode`] override).
+    pub fn status(&self) -> AsepritePlaybackStatus {
+        match self.remaining_repeats {
+            None => AsepritePlaybackStatus::Playing,
+            Some(0) => AsepritePlaybackStatus::Stopped,
+            Some(_) => AsepritePlaybackStatus::Repeating,
+        }
+    }
+}
+
+/// What a single [`AsepriteAnimation::update`] call did, so the calling
+/// system can fire the matching events.
+#[derive(Debug, Default)]
+struct AnimationUpdate {
+    frame_changed: bool,
+    /// Number of full cycles completed during this update (can be more than
+    /// one for a large `dt` or a high `|speed|`).
+    loop_count: u32,
+    finished: bool,
 }
 
 pub(crate) fn update_animations(
     time: Res<Time>,
     aseprites: Res<Assets<Aseprite>>,
     mut aseprites_query: Query<(
+        Entity,
         &Handle<Aseprite>,
         &mut AsepriteAnimation,
         &mut TextureAtlasSprite,
     )>,
+    mut looped_events: EventWriter<AsepriteAnimationLooped>,
+    mut finished_events: EventWriter<AsepriteAnimationFinished>,
 ) {
-    for (handle, mut animation, mut sprite) in aseprites_query.iter_mut() {
+    for (entity, handle, mut animation, mut sprite) in aseprites_query.iter_mut() {
         let aseprite = match aseprites.get(handle) {
             Some(aseprite) => aseprite,
             None => {
@@ -234,7 +516,20 @@ pub(crate) fn update_animations(
                 continue;
             }
         };
-        if animation.update(&aseprite.info, time.delta()) {
+        let update = animation.update(&aseprite.info, time.delta());
+        for _ in 0..update.loop_count {
+            looped_events.send(AsepriteAnimationLooped {
+                entity,
+                tag: animation.tag.clone(),
+            });
+        }
+        if update.finished {
+            finished_events.send(AsepriteAnimationFinished {
+                entity,
+                tag: animation.tag.clone(),
+            });
+        }
+        if update.frame_changed {
             if let Some(index) = aseprite.atlas.frame_to_idx(animation.current_frame) {
                 sprite.index = index;
             }
@@ -258,4 +553,233 @@ impl From<String> for AsepriteAnimation {
             ..default()
         }
     }
-}
\ No newline at end of file
+}
+
+/// A queue of tags to play in sequence after the current one, e.g. to
+/// script a combo like "attack -> recover -> idle" without per-frame
+/// bookkeeping in user systems.
+#[derive(Debug, Default, Component)]
+pub struct AsepriteAnimationPlaylist {
+    queue: VecDeque<(AsepriteTag, RepeatMode)>,
+}
+
+impl AsepriteAnimationPlaylist {
+    /// Creates an empty playlist.
+    pub fn new() -> Self {
+        default()
+    }
+
+    /// Queues `tag` to play once the current tag finishes, using `tag`'s own
+    /// authored repeat count.
+    pub fn then(mut self, tag: AsepriteTag) -> Self {
+        self.queue.push_back((tag, RepeatMode::Tag));
+        self
+    }
+
+    /// Queues `tag` to play once the current tag finishes, repeating it
+    /// `count` times regardless of its authored repeat count.
+    pub fn then_repeat(mut self, tag: AsepriteTag, count: u32) -> Self {
+        self.queue.push_back((tag, RepeatMode::Count(count)));
+        self
+    }
+
+    /// Empties the queue. The tag currently playing is left untouched.
+    pub fn clear_queue(&mut self) {
+        self.queue.clear();
+    }
+
+    /// Returns how many tags are still queued behind the one currently
+    /// playing.
+    pub fn queued_len(&self) -> usize {
+        self.queue.len()
+    }
+}
+
+/// Advances each entity's playlist: once its [`AsepriteAnimation`] has
+/// stopped (its finite-repeat tag exhausted all its repeats), pops the next
+/// queued tag and hands it to the animation. Should run after
+/// [`update_animations`].
+pub(crate) fn update_playlists(
+    mut query: Query<(&mut AsepriteAnimation, &mut AsepriteAnimationPlaylist)>,
+) {
+    for (mut animation, mut playlist) in query.iter_mut() {
+        if animation.status() != AsepritePlaybackStatus::Stopped {
+            continue;
+        }
+        let Some((tag, repeat_mode)) = playlist.queue.pop_front() else {
+            continue;
+        };
+        animation.tag = Some(tag.0.to_owned());
+        animation.tag_changed = true;
+        animation.repeat_override = Some(repeat_mode);
+        animation.is_playing = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reader::raw::AsepriteAnimationDirection;
+
+    /// `update()`'s catch-up loop accumulates one completed cycle per
+    /// `step_frame` call rather than coalescing them into a single flag, so
+    /// a large `dt` (or a high `|speed|`) still yields one
+    /// `AsepriteAnimationLooped` per cycle instead of at most one total.
+    #[test]
+    fn multiple_completed_cycles_in_one_tick_are_all_counted() {
+        let range = 0..2u16;
+        let mut current_frame = 0usize;
+        let mut forward = true;
+        let mut loop_count = 0u32;
+        for _ in 0..5 {
+            let (next_frame, next_forward, cycle_completed) = step_frame(
+                current_frame,
+                forward,
+                AsepriteAnimationDirection::Forward,
+                &range,
+            );
+            current_frame = next_frame;
+            forward = next_forward;
+            if cycle_completed {
+                loop_count += 1;
+            }
+        }
+        assert_eq!(loop_count, 2);
+    }
+
+    /// A zero (or otherwise non-positive) `max_frame_duration` must not make
+    /// `update()`'s `while time_elapsed >= current_frame_duration` loop spin
+    /// forever; it should be ignored instead.
+    #[test]
+    fn zero_max_frame_duration_is_a_no_op() {
+        let authored = Duration::from_millis(100);
+        assert_eq!(
+            clamp_frame_duration(authored, Some(Duration::ZERO)),
+            authored
+        );
+    }
+
+    #[test]
+    fn max_frame_duration_clamps_long_frames() {
+        let authored = Duration::from_millis(1000);
+        let max = Duration::from_millis(50);
+        assert_eq!(clamp_frame_duration(authored, Some(max)), max);
+        assert_eq!(clamp_frame_duration(authored, None), authored);
+    }
+
+    #[test]
+    fn forward_wraps_to_range_start() {
+        let range = 2..5u16;
+        assert_eq!(
+            step_frame(3, true, AsepriteAnimationDirection::Forward, &range),
+            (4, true, false)
+        );
+        assert_eq!(
+            step_frame(4, true, AsepriteAnimationDirection::Forward, &range),
+            (2, true, true)
+        );
+    }
+
+    #[test]
+    fn reverse_wraps_to_range_end() {
+        let range = 2..5u16;
+        assert_eq!(
+            step_frame(4, false, AsepriteAnimationDirection::Reverse, &range),
+            (3, false, false)
+        );
+        assert_eq!(
+            step_frame(2, false, AsepriteAnimationDirection::Reverse, &range),
+            (4, false, true)
+        );
+    }
+
+    /// Regression test: the backward leg used to re-increment `current_frame`
+    /// unconditionally right after decrementing it, so it could never reach
+    /// `range.start` and a finite-repeat PingPong tag would loop forever.
+    #[test]
+    fn pingpong_backward_leg_actually_steps_backward_and_completes_at_start() {
+        let range = 2..5u16;
+
+        // Turn around at the end: holds on the last frame for one tick while
+        // flipping direction.
+        assert_eq!(
+            step_frame(4, true, AsepriteAnimationDirection::PingPong, &range),
+            (4, false, false)
+        );
+        // Step backward one frame at a time, never completing early.
+        assert_eq!(
+            step_frame(4, false, AsepriteAnimationDirection::PingPong, &range),
+            (3, false, false)
+        );
+        assert_eq!(
+            step_frame(3, false, AsepriteAnimationDirection::PingPong, &range),
+            (2, false, false)
+        );
+        // One full there-and-back: backward leg returns to range.start.
+        assert_eq!(
+            step_frame(2, false, AsepriteAnimationDirection::PingPong, &range),
+            (2, true, true)
+        );
+    }
+
+    #[test]
+    fn default_animation_is_playing_at_normal_speed_with_no_repeat_limit() {
+        let animation = AsepriteAnimation::default();
+        assert!(animation.is_playing());
+        assert_eq!(animation.speed(), 1.0);
+        assert_eq!(animation.max_frame_duration(), None);
+        assert_eq!(animation.status(), AsepritePlaybackStatus::Playing);
+    }
+
+    #[test]
+    fn status_reflects_remaining_repeats() {
+        let mut animation = AsepriteAnimation::default();
+        assert_eq!(animation.status(), AsepritePlaybackStatus::Playing);
+
+        animation.remaining_repeats = Some(2);
+        assert_eq!(animation.status(), AsepritePlaybackStatus::Repeating);
+
+        animation.remaining_repeats = Some(0);
+        assert_eq!(animation.status(), AsepritePlaybackStatus::Stopped);
+    }
+
+    #[test]
+    fn speed_and_max_frame_duration_accessors_round_trip() {
+        let mut animation = AsepriteAnimation::default();
+        animation.set_speed(-2.5);
+        assert_eq!(animation.speed(), -2.5);
+
+        animation.set_max_frame_duration(Some(Duration::from_millis(50)));
+        assert_eq!(
+            animation.max_frame_duration(),
+            Some(Duration::from_millis(50))
+        );
+    }
+
+    #[test]
+    fn playlist_builder_queues_tags_in_order() {
+        const ATTACK: AsepriteTag = AsepriteTag::new("attack");
+        const RECOVER: AsepriteTag = AsepriteTag::new("recover");
+
+        let playlist = AsepriteAnimationPlaylist::new()
+            .then(ATTACK)
+            .then_repeat(RECOVER, 3);
+        assert_eq!(playlist.queued_len(), 2);
+        assert_eq!(playlist.queue[0], (ATTACK, RepeatMode::Tag));
+        assert_eq!(playlist.queue[1], (RECOVER, RepeatMode::Count(3)));
+    }
+
+    #[test]
+    fn clear_queue_empties_the_playlist() {
+        let mut playlist = AsepriteAnimationPlaylist::new().then(AsepriteTag::new("idle"));
+        assert_eq!(playlist.queued_len(), 1);
+        playlist.clear_queue();
+        assert_eq!(playlist.queued_len(), 0);
+    }
+
+    #[test]
+    fn tag_deref_and_new_are_consistent() {
+        const JUMP: AsepriteTag = AsepriteTag::new("jump");
+        assert_eq!(*JUMP, "jump");
+    }
+}
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+mod anim;
+
+pub use anim::{
+    AsepriteAnimation, AsepriteAnimationFinished, AsepriteAnimationLooped,
+    AsepriteAnimationPlaylist, AsepritePlaybackStatus, AsepriteTag, RepeatMode,
+};
+
+/// Drives Aseprite animation playback: advances every
+/// [`AsepriteAnimation`] each frame, fires [`AsepriteAnimationLooped`] /
+/// [`AsepriteAnimationFinished`] as tags complete cycles or run out of
+/// repeats, and advances [`AsepriteAnimationPlaylist`] queues once their
+/// animation stops.
+pub struct AsepritePlugin;
+
+impl Plugin for AsepritePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AsepriteAnimationLooped>()
+            .add_event::<AsepriteAnimationFinished>()
+            .add_systems(
+                Update,
+                (
+                    anim::update_animations,
+                    anim::update_playlists.after(anim::update_animations),
+                ),
+            );
+    }
+}